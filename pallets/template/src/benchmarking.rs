@@ -4,16 +4,59 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as Template;
-use frame_benchmarking::v1::{benchmarks, whitelisted_caller};
+use frame_benchmarking::v2::*;
 use frame_system::RawOrigin;
 
-benchmarks! {
-	do_something {
-		let s in 0 .. 100;
+#[instance_benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn do_something(s: Linear<0, 100>) {
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), s);
+
+		assert_eq!(Something::<T, I>::get(), Some(s));
+	}
+
+	#[benchmark]
+	fn cause_error() {
+		Something::<T, I>::put(100u32);
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller));
+
+		assert_eq!(Something::<T, I>::get(), Some(101u32));
+	}
+
+	// Error path: nothing stored yet, so the call bails out after a single read.
+	#[benchmark]
+	fn cause_error_none_value() {
 		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), s)
-	verify {
-		assert_eq!(Something::<T>::get(), Some(s));
+
+		#[block]
+		{
+			assert!(Template::<T, I>::cause_error(RawOrigin::Signed(caller).into()).is_err());
+		}
+
+		assert_eq!(Something::<T, I>::get(), None);
+	}
+
+	// Error path: the stored value cannot be incremented, so the call bails out after the read.
+	#[benchmark]
+	fn cause_error_storage_overflow() {
+		Something::<T, I>::put(u32::MAX);
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[block]
+		{
+			assert!(Template::<T, I>::cause_error(RawOrigin::Signed(caller).into()).is_err());
+		}
+
+		assert_eq!(Something::<T, I>::get(), Some(u32::MAX));
 	}
 
 	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
@@ -16,17 +16,19 @@ mod benchmarking;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use frame_support::pallet_prelude::*;
+	use frame_support::{dispatch::WithPostDispatchInfo, pallet_prelude::*};
 	use frame_system::pallet_prelude::*;
 
 	#[pallet::pallet]
-	pub struct Pallet<T>(_);
+	pub struct Pallet<T, I = ()>(_);
 
-	/// Configure the pallet by specifying the parameters and types on which it depends.
+	/// Configure the pallet by specifying the parameters and types on which it depends. The pallet
+	/// is instantiable, so a runtime can include it several times with independent storage.
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config<I: 'static = ()>: frame_system::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
-		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
 	}
 
 	// The pallet's runtime storage items.
@@ -35,13 +37,13 @@ pub mod pallet {
 	#[pallet::getter(fn something)]
 	// Learn more about declaring storage items:
 	// https://docs.substrate.io/main-docs/build/runtime-storage/#declaring-storage-items
-	pub type Something<T> = StorageValue<_, u32>;
+	pub type Something<T: Config<I>, I: 'static = ()> = StorageValue<_, u32>;
 
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/main-docs/build/events-errors/
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
+	pub enum Event<T: Config<I>, I: 'static = ()> {
 		/// Event documentation should end with an array that provides descriptive names for event
 		/// parameters. [something, who]
 		SomethingStored { something: u32, who: T::AccountId },
@@ -49,7 +51,7 @@ pub mod pallet {
 
 	// Errors inform users that something went wrong.
 	#[pallet::error]
-	pub enum Error<T> {
+	pub enum Error<T, I = ()> {
 		/// Error names should be descriptive.
 		NoneValue,
 		/// Errors should have helpful documentation associated with them.
@@ -60,7 +62,7 @@ pub mod pallet {
 	// These functions materialize as "extrinsics", which are often compared to transactions.
 	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// An example dispatchable that takes a singles value as a parameter, writes the value to
 		/// storage and emits an event. This function must be dispatched by a signed extrinsic.
 		#[pallet::call_index(0)]
@@ -72,7 +74,7 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 
 			// Update storage.
-			<Something<T>>::put(something);
+			<Something<T, I>>::put(something);
 
 			// Emit an event.
 			Self::deposit_event(Event::SomethingStored { something, who });
@@ -80,24 +82,41 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// An example dispatchable that may throw a custom error.
+		/// An example dispatchable that may throw a custom error. When it fails before writing,
+		/// the weight of the skipped write is refunded.
 		#[pallet::call_index(1)]
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
-		pub fn cause_error(origin: OriginFor<T>) -> DispatchResult {
+		#[pallet::weight(
+			Pallet::<T, I>::cause_error_read_only_weight()
+				.saturating_add(T::DbWeight::get().writes(1))
+		)]
+		pub fn cause_error(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			let _who = ensure_signed(origin)?;
 
+			// Weight actually used when the call fails before writing.
+			let read_only_weight = Self::cause_error_read_only_weight();
+
 			// Read a value from storage.
-			match <Something<T>>::get() {
-				// Return an error if the value has not been set.
-				None => return Err(Error::<T>::NoneValue.into()),
+			match <Something<T, I>>::get() {
+				// Return an error if the value has not been set, charging only for the read.
+				None => Err(Error::<T, I>::NoneValue.with_weight(read_only_weight)),
 				Some(old) => {
-					// Increment the value read from storage; will error in the event of overflow.
-					let new = old.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
+					// Increment the value read from storage; will error in the event of overflow,
+					// again charging only for the read.
+					let new = old
+						.checked_add(1)
+						.ok_or(Error::<T, I>::StorageOverflow.with_weight(read_only_weight))?;
 					// Update the value in storage with the incremented result.
-					<Something<T>>::put(new);
-					Ok(())
+					<Something<T, I>>::put(new);
+					Ok(().into())
 				},
 			}
 		}
 	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Weight of `cause_error` when it bails out after reading `Something`, without the write.
+		pub fn cause_error_read_only_weight() -> Weight {
+			Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads(1))
+		}
+	}
 }
@@ -1,5 +1,9 @@
 use crate as pallet_template;
-use frame_support::traits::{ConstU16, ConstU64};
+use frame_support::{
+	instances::Instance1,
+	traits::{ConstU16, ConstU64},
+	weights::constants::RocksDbWeight,
+};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
@@ -18,6 +22,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system,
 		TemplateModule: pallet_template,
+		SecondTemplateModule: pallet_template::<Instance1>,
 	}
 );
 
@@ -25,7 +30,7 @@ impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
 	type BlockLength = ();
-	type DbWeight = ();
+	type DbWeight = RocksDbWeight;
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
 	type Index = u64;
@@ -52,6 +57,10 @@ impl pallet_template::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 }
 
+impl pallet_template::Config<Instance1> for Test {
+	type RuntimeEvent = RuntimeEvent;
+}
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
@@ -1,5 +1,17 @@
 use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::{GetDispatchInfo, WithPostDispatchInfo},
+	instances::Instance1,
+	traits::Get,
+	weights::Weight,
+};
+
+/// Declared weight of `cause_error` minus the write it skips when failing early.
+fn cause_error_weight_without_write() -> Weight {
+	let declared = crate::Call::<Test>::cause_error {}.get_dispatch_info().weight;
+	declared.saturating_sub(<Test as frame_system::Config>::DbWeight::get().writes(1))
+}
 
 #[test]
 fn it_works_for_default_value() {
@@ -11,7 +23,22 @@ fn it_works_for_default_value() {
 		// Read pallet storage and assert an expected result.
 		assert_eq!(TemplateModule::something(), Some(42));
 		// Assert that the correct event was deposited
-		System::assert_last_event(Event::SomethingStored { something: 42, who: 1 }.into());
+		System::assert_last_event(Event::<Test>::SomethingStored { something: 42, who: 1 }.into());
+	});
+}
+
+#[test]
+fn instances_have_separate_storage() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), 42));
+		assert_ok!(SecondTemplateModule::do_something(RuntimeOrigin::signed(2), 7));
+		// Each instance keeps its own value and deposits its own event.
+		assert_eq!(TemplateModule::something(), Some(42));
+		assert_eq!(SecondTemplateModule::something(), Some(7));
+		System::assert_last_event(
+			Event::<Test, Instance1>::SomethingStored { something: 7, who: 2 }.into(),
+		);
 	});
 }
 
@@ -21,7 +48,30 @@ fn correct_error_for_none_value() {
 		// Ensure the expected error is thrown when no value is present.
 		assert_noop!(
 			TemplateModule::cause_error(RuntimeOrigin::signed(1)),
-			Error::<Test>::NoneValue
+			Error::<Test>::NoneValue.with_weight(cause_error_weight_without_write())
 		);
 	});
 }
+
+#[test]
+fn correct_error_for_storage_overflow() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), u32::MAX));
+		// Incrementing would overflow, so the call fails after the read and refunds the write.
+		assert_noop!(
+			TemplateModule::cause_error(RuntimeOrigin::signed(1)),
+			Error::<Test>::StorageOverflow.with_weight(cause_error_weight_without_write())
+		);
+	});
+}
+
+#[test]
+fn cause_error_charges_full_weight_on_success() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), 42));
+		let info = TemplateModule::cause_error(RuntimeOrigin::signed(1)).unwrap();
+		// No post-dispatch correction, so the declared weight is charged.
+		assert_eq!(info.actual_weight, None);
+		assert_eq!(TemplateModule::something(), Some(43));
+	});
+}